@@ -19,6 +19,18 @@ pub trait InfiniteStream {
         }
     }
 
+    fn fork<FA, FB, A, B>(self, left: FA, right: FB) -> Fork<Self, FA, FB>
+        where FA: FnMut(&Self::Item) -> A,
+              FB: FnMut(&Self::Item) -> B,
+              Self: Sized
+    {
+        Fork {
+            stream: self,
+            left: left,
+            right: right,
+        }
+    }
+
     fn merge<S>(self, other: S) -> Merge<Self, S>
         where S: InfiniteStream,
               Self: Sized
@@ -119,6 +131,35 @@ impl<S, F, B> InfiniteStream for Map<S, F>
     }
 }
 
+#[must_use = "streams do nothing unless polled"]
+pub struct Fork<S, FA, FB> {
+    stream: S,
+    left: FA,
+    right: FB,
+}
+
+impl<S, FA, FB, A, B> InfiniteStream for Fork<S, FA, FB>
+    where S: InfiniteStream,
+          FA: FnMut(&S::Item) -> A,
+          FB: FnMut(&S::Item) -> B
+{
+    type Item = ForkedItem<A, B>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        self.stream.poll().map(|item| {
+            ForkedItem {
+                left: (self.left)(&item),
+                right: (self.right)(&item),
+            }
+        })
+    }
+}
+
+pub struct ForkedItem<A, B> {
+    pub left: A,
+    pub right: B,
+}
+
 #[must_use = "streams do nothing unless polled"]
 pub struct Merge<A, B> {
     a: A,
@@ -4,7 +4,9 @@ pub mod prelude;
 
 mod infinite_stream;
 mod stream;
+mod try_future;
 
+use core::marker::PhantomData;
 use core::mem;
 
 pub enum Async<T> {
@@ -34,6 +36,89 @@ impl<T> Async<T> {
     }
 }
 
+/// Creates a future that immediately resolves to `t` on its first poll.
+pub fn ready<T>(t: T) -> Ready<T> {
+    Ready { item: Some(t) }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct Ready<T> {
+    item: Option<T>,
+}
+
+impl<T> Future for Ready<T> {
+    type Item = T;
+
+    fn poll(&mut self) -> Async<T> {
+        Async::Ready(self.item.take().expect("cannot poll `ready` twice"))
+    }
+}
+
+/// Creates a future that never resolves.
+pub fn pending<T>() -> Pending<T> {
+    Pending { _item: PhantomData }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct Pending<T> {
+    _item: PhantomData<T>,
+}
+
+impl<T> Future for Pending<T> {
+    type Item = T;
+
+    fn poll(&mut self) -> Async<T> {
+        Async::NotReady
+    }
+}
+
+/// Creates a future that defers running `f` until its first poll, then
+/// immediately resolves to its result.
+pub fn lazy<F, T>(f: F) -> Lazy<F>
+    where F: FnOnce() -> T
+{
+    Lazy { f: Some(f) }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct Lazy<F> {
+    f: Option<F>,
+}
+
+impl<F, T> Future for Lazy<F>
+    where F: FnOnce() -> T
+{
+    type Item = T;
+
+    fn poll(&mut self) -> Async<T> {
+        let f = self.f.take().expect("cannot poll `lazy` twice");
+
+        Async::Ready(f())
+    }
+}
+
+/// Adapts an arbitrary polling closure into a `Future`.
+pub fn poll_fn<F, T>(f: F) -> PollFn<F>
+    where F: FnMut() -> Async<T>
+{
+    PollFn { f: f }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct PollFn<F> {
+    f: F,
+}
+
+impl<F, T> Future for PollFn<F>
+    where F: FnMut() -> Async<T>
+{
+    type Item = T;
+
+    fn poll(&mut self) -> Async<T> {
+        (self.f)()
+    }
+}
+
 pub trait Future {
     type Item;
 
@@ -310,8 +395,225 @@ impl<A, B> Future for SelectNext<A, B>
     }
 }
 
+/// Either the first or the second value of a [`select`](fn.select.html).
+pub enum Either<X, Y> {
+    First(X),
+    Second(Y),
+}
+
+/// Races two futures, resolving with whichever finishes first and dropping
+/// the other. Unlike [`Future::select`](trait.Future.html#method.select),
+/// the loser isn't handed back to the caller.
+pub fn select<A, B>(a: A, b: B) -> SelectEither<A, B>
+    where A: Future,
+          B: Future
+{
+    SelectEither { state: Some((a, b)) }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct SelectEither<A, B> {
+    state: Option<(A, B)>,
+}
+
+impl<A, B> Future for SelectEither<A, B>
+    where A: Future,
+          B: Future
+{
+    type Item = Either<A::Item, B::Item>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let (mut a, mut b) =
+            self.state.take().expect("cannot poll `select` twice");
+
+        match a.poll() {
+            Async::Ready(a) => Async::Ready(Either::First(a)),
+            Async::NotReady => {
+                match b.poll() {
+                    Async::Ready(b) => Async::Ready(Either::Second(b)),
+                    Async::NotReady => {
+                        self.state = Some((a, b));
+                        Async::NotReady
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Races a slice of futures, resolving with the index and item of the first
+/// one that's ready.
+///
+/// Because this crate is `no_std`, the futures are borrowed from the caller
+/// rather than collected into a heap-allocated `Vec`.
+pub fn select_all<F>(futures: &mut [F]) -> SelectAll<F>
+    where F: Future
+{
+    SelectAll { futures: futures }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct SelectAll<'f, F>
+    where F: Future + 'f
+{
+    futures: &'f mut [F],
+}
+
+impl<'f, F> Future for SelectAll<'f, F>
+    where F: Future
+{
+    type Item = (usize, F::Item);
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        for (i, future) in self.futures.iter_mut().enumerate() {
+            if let Async::Ready(item) = future.poll() {
+                return Async::Ready((i, item));
+            }
+        }
+
+        Async::NotReady
+    }
+}
+
+/// Races a slice of fallible futures, skipping over `Err`s and resolving
+/// with the index and value of the first one to succeed. If every future in
+/// the slice has failed, resolves with the last error observed.
+///
+/// As with `select_all`, futures that have already failed are polled again
+/// on subsequent rounds; this is harmless for the kind of short-lived leaf
+/// futures this combinator is meant to race, but means it isn't suitable for
+/// one-shot combinators that panic when polled past completion.
+pub fn select_ok<F, T, E>(futures: &mut [F]) -> SelectOk<F>
+    where F: Future<Item = Result<T, E>>
+{
+    SelectOk { futures: futures }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct SelectOk<'f, F>
+    where F: Future + 'f
+{
+    futures: &'f mut [F],
+}
+
+impl<'f, F, T, E> Future for SelectOk<'f, F>
+    where F: Future<Item = Result<T, E>>
+{
+    type Item = Result<(usize, T), E>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let mut last_err = None;
+        let mut all_failed = true;
+
+        for (i, future) in self.futures.iter_mut().enumerate() {
+            match future.poll() {
+                Async::Ready(Ok(t)) => return Async::Ready(Ok((i, t))),
+                Async::Ready(Err(e)) => last_err = Some(e),
+                Async::NotReady => all_failed = false,
+            }
+        }
+
+        if all_failed {
+            Async::Ready(Err(last_err.expect("`select_ok` polled with no futures")))
+        } else {
+            Async::NotReady
+        }
+    }
+}
+
 pub trait InfiniteIterator {
     type Item;
 
     fn next(&mut self) -> Self::Item;
 }
+
+/// A single slot of a variadic `join`: either still running, holding its
+/// finished item, or already handed off.
+enum MaybeDone<F>
+    where F: Future
+{
+    NotYet(F),
+    Done(F::Item),
+    Gone,
+}
+
+impl<F> MaybeDone<F>
+    where F: Future
+{
+    /// Polls the inner future if it hasn't finished yet. Returns `true` once
+    /// this slot holds a finished item.
+    fn poll(&mut self) -> bool {
+        let item = match *self {
+            MaybeDone::NotYet(ref mut f) => {
+                match f.poll() {
+                    Async::Ready(item) => item,
+                    Async::NotReady => return false,
+                }
+            }
+            MaybeDone::Done(..) => return true,
+            MaybeDone::Gone => panic!("cannot poll `MaybeDone` twice"),
+        };
+
+        *self = MaybeDone::Done(item);
+        true
+    }
+
+    /// Takes the finished item out of this slot. Must only be called after
+    /// `poll` has returned `true`.
+    fn take(&mut self) -> F::Item {
+        match mem::replace(self, MaybeDone::Gone) {
+            MaybeDone::Done(item) => item,
+            _ => panic!("cannot `take` a `MaybeDone` that isn't done"),
+        }
+    }
+}
+
+macro_rules! generate_join {
+    ($join:ident, $Join:ident, <$($F:ident),+>, ($($f:ident),+)) => {
+        #[must_use = "futures do nothing unless polled"]
+        pub struct $Join<$($F),+>
+            where $($F: Future),+
+        {
+            $($f: MaybeDone<$F>,)+
+        }
+
+        /// Joins the given futures, resolving once every one of them has
+        /// resolved, with a tuple of all their items.
+        pub fn $join<$($F),+>($($f: $F),+) -> $Join<$($F),+>
+            where $($F: Future),+
+        {
+            $Join {
+                $($f: MaybeDone::NotYet($f),)+
+            }
+        }
+
+        impl<$($F),+> Future for $Join<$($F),+>
+            where $($F: Future),+
+        {
+            type Item = ($($F::Item),+);
+
+            fn poll(&mut self) -> Async<Self::Item> {
+                let mut all_done = true;
+
+                $(
+                    if !self.$f.poll() {
+                        all_done = false;
+                    }
+                )+
+
+                if all_done {
+                    Async::Ready(($(self.$f.take()),+))
+                } else {
+                    Async::NotReady
+                }
+            }
+        }
+    }
+}
+
+generate_join!(join3, Join3, <A, B, C>, (a, b, c));
+generate_join!(join4, Join4, <A, B, C, D>, (a, b, c, d));
+generate_join!(join5, Join5, <A, B, C, D, E>, (a, b, c, d, e));
+generate_join!(join6, Join6, <A, B, C, D, E, F>, (a, b, c, d, e, f));
+generate_join!(join7, Join7, <A, B, C, D, E, F, G>, (a, b, c, d, e, f, g));
+generate_join!(join8, Join8, <A, B, C, D, E, F, G, H>, (a, b, c, d, e, f, g, h));
@@ -1,10 +1,60 @@
-use Async;
+use core::mem;
+
+use {Async, Future};
 
 pub trait Stream {
     type Item;
 
     fn poll(&mut self) -> Async<Option<Self::Item>>;
 
+    fn filter<F>(self, f: F) -> Filter<Self, F>
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized
+    {
+        Filter { stream: self, f: f }
+    }
+
+    fn filter_map<F, B>(self, f: F) -> FilterMap<Self, F>
+        where F: FnMut(Self::Item) -> Option<B>,
+              Self: Sized
+    {
+        FilterMap { stream: self, f: f }
+    }
+
+    fn fold<T, F, B>(self, init: T, f: F) -> Fold<Self, F, B, T>
+        where F: FnMut(T, Self::Item) -> B,
+              B: Future<Item = T>,
+              Self: Sized
+    {
+        Fold { state: State::Ready(init), f: f, stream: self }
+    }
+
+    fn for_each<F>(self, f: F) -> ForEach<Self, F>
+        where F: FnMut(Self::Item),
+              Self: Sized
+    {
+        ForEach { stream: self, f: f }
+    }
+
+    fn map<F, B>(self, f: F) -> Map<Self, F>
+        where F: FnMut(Self::Item) -> B,
+              Self: Sized
+    {
+        Map { stream: self, f: f }
+    }
+
+    fn skip(self, n: u64) -> Skip<Self>
+        where Self: Sized
+    {
+        Skip { stream: self, remaining: n }
+    }
+
+    fn take(self, n: u64) -> Take<Self>
+        where Self: Sized
+    {
+        Take { stream: self, remaining: n }
+    }
+
     fn wait(self) -> Wait<Self>
         where Self: Sized
     {
@@ -12,6 +62,204 @@ pub trait Stream {
     }
 }
 
+#[must_use = "streams do nothing unless polled"]
+pub struct Filter<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F> Stream for Filter<S, F>
+    where S: Stream,
+          F: FnMut(&S::Item) -> bool
+{
+    type Item = S::Item;
+
+    fn poll(&mut self) -> Async<Option<Self::Item>> {
+        loop {
+            match self.stream.poll() {
+                Async::NotReady => return Async::NotReady,
+                Async::Ready(None) => return Async::Ready(None),
+                Async::Ready(Some(item)) => {
+                    if (self.f)(&item) {
+                        return Async::Ready(Some(item));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct FilterMap<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F, B> Stream for FilterMap<S, F>
+    where S: Stream,
+          F: FnMut(S::Item) -> Option<B>
+{
+    type Item = B;
+
+    fn poll(&mut self) -> Async<Option<Self::Item>> {
+        loop {
+            match self.stream.poll() {
+                Async::NotReady => return Async::NotReady,
+                Async::Ready(None) => return Async::Ready(None),
+                Async::Ready(Some(item)) => {
+                    if let Some(item) = (self.f)(item) {
+                        return Async::Ready(Some(item));
+                    }
+                }
+            }
+        }
+    }
+}
+
+enum State<T, B> {
+    Ready(T),
+    Processing(B),
+    Empty,
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct Fold<S, F, B, T> {
+    state: State<T, B>,
+    f: F,
+    stream: S,
+}
+
+impl<S, F, B, T> Future for Fold<S, F, B, T>
+    where S: Stream,
+          F: FnMut(T, S::Item) -> B,
+          B: Future<Item = T>
+{
+    type Item = T;
+
+    fn poll(&mut self) -> Async<T> {
+        loop {
+            match mem::replace(&mut self.state, State::Empty) {
+                State::Ready(acc) => {
+                    match self.stream.poll() {
+                        Async::NotReady => {
+                            self.state = State::Ready(acc);
+                            return Async::NotReady;
+                        }
+                        Async::Ready(Some(item)) => {
+                            self.state = State::Processing((self.f)(acc, item));
+                        }
+                        Async::Ready(None) => return Async::Ready(acc),
+                    }
+                }
+                State::Processing(mut future) => {
+                    match future.poll() {
+                        Async::NotReady => {
+                            self.state = State::Processing(future);
+                            return Async::NotReady;
+                        }
+                        Async::Ready(acc) => self.state = State::Ready(acc),
+                    }
+                }
+                State::Empty => panic!("cannot poll `fold` twice"),
+            }
+        }
+    }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct ForEach<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F> Future for ForEach<S, F>
+    where S: Stream,
+          F: FnMut(S::Item)
+{
+    type Item = ();
+
+    fn poll(&mut self) -> Async<()> {
+        loop {
+            match self.stream.poll() {
+                Async::NotReady => return Async::NotReady,
+                Async::Ready(None) => return Async::Ready(()),
+                Async::Ready(Some(item)) => (self.f)(item),
+            }
+        }
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Map<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F, B> Stream for Map<S, F>
+    where S: Stream,
+          F: FnMut(S::Item) -> B
+{
+    type Item = B;
+
+    fn poll(&mut self) -> Async<Option<Self::Item>> {
+        self.stream.poll().map(|item| item.map(|item| (self.f)(item)))
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Skip<S> {
+    stream: S,
+    remaining: u64,
+}
+
+impl<S> Stream for Skip<S>
+    where S: Stream
+{
+    type Item = S::Item;
+
+    fn poll(&mut self) -> Async<Option<Self::Item>> {
+        while self.remaining > 0 {
+            match self.stream.poll() {
+                Async::NotReady => return Async::NotReady,
+                Async::Ready(None) => return Async::Ready(None),
+                Async::Ready(Some(_)) => self.remaining -= 1,
+            }
+        }
+
+        self.stream.poll()
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Take<S> {
+    stream: S,
+    remaining: u64,
+}
+
+impl<S> Stream for Take<S>
+    where S: Stream
+{
+    type Item = S::Item;
+
+    fn poll(&mut self) -> Async<Option<Self::Item>> {
+        if self.remaining == 0 {
+            return Async::Ready(None);
+        }
+
+        match self.stream.poll() {
+            Async::NotReady => Async::NotReady,
+            Async::Ready(None) => {
+                self.remaining = 0;
+                Async::Ready(None)
+            }
+            Async::Ready(Some(item)) => {
+                self.remaining -= 1;
+                Async::Ready(Some(item))
+            }
+        }
+    }
+}
+
 pub struct Wait<S> {
     stream: S,
 }
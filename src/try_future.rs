@@ -0,0 +1,195 @@
+use core::mem;
+
+use {Async, Future};
+
+/// A `Future` that resolves to a `Result`, with combinators that short-circuit
+/// on `Err` the way `Result`'s own `and_then`/`or_else` do.
+pub trait TryFuture: Future<Item = Result<<Self as TryFuture>::Ok, <Self as TryFuture>::Error>> {
+    type Ok;
+    type Error;
+
+    fn map_ok<F, T>(self, f: F) -> MapOk<Self, F>
+        where F: FnOnce(Self::Ok) -> T,
+              Self: Sized
+    {
+        MapOk {
+            future: self,
+            f: Some(f),
+        }
+    }
+
+    fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
+        where F: FnOnce(Self::Error) -> E,
+              Self: Sized
+    {
+        MapErr {
+            future: self,
+            f: Some(f),
+        }
+    }
+
+    fn and_then<F, B>(self, f: F) -> AndThen<Self, B, F>
+        where F: FnOnce(Self::Ok) -> B,
+              B: TryFuture<Error = Self::Error>,
+              Self: Sized
+    {
+        AndThen::First(self, f)
+    }
+
+    fn or_else<F, B>(self, f: F) -> OrElse<Self, B, F>
+        where F: FnOnce(Self::Error) -> B,
+              B: TryFuture<Ok = Self::Ok>,
+              Self: Sized
+    {
+        OrElse::First(self, f)
+    }
+}
+
+impl<F, T, E> TryFuture for F
+    where F: Future<Item = Result<T, E>>
+{
+    type Ok = T;
+    type Error = E;
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct MapOk<A, F> {
+    future: A,
+    f: Option<F>,
+}
+
+impl<A, F, T> Future for MapOk<A, F>
+    where A: TryFuture,
+          F: FnOnce(A::Ok) -> T
+{
+    type Item = Result<T, A::Error>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let f = self.f.take().expect("cannot poll `map_ok` twice");
+
+        match self.future.poll() {
+            Async::NotReady => {
+                self.f = Some(f);
+                Async::NotReady
+            }
+            Async::Ready(Ok(ok)) => Async::Ready(Ok(f(ok))),
+            Async::Ready(Err(e)) => Async::Ready(Err(e)),
+        }
+    }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub struct MapErr<A, F> {
+    future: A,
+    f: Option<F>,
+}
+
+impl<A, F, E> Future for MapErr<A, F>
+    where A: TryFuture,
+          F: FnOnce(A::Error) -> E
+{
+    type Item = Result<A::Ok, E>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let f = self.f.take().expect("cannot poll `map_err` twice");
+
+        match self.future.poll() {
+            Async::NotReady => {
+                self.f = Some(f);
+                Async::NotReady
+            }
+            Async::Ready(Ok(ok)) => Async::Ready(Ok(ok)),
+            Async::Ready(Err(e)) => Async::Ready(Err(f(e))),
+        }
+    }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub enum AndThen<A, B, F> {
+    First(A, F),
+    Second(B),
+    Done,
+}
+
+impl<A, B, F> Future for AndThen<A, B, F>
+    where A: TryFuture,
+          F: FnOnce(A::Ok) -> B,
+          B: TryFuture<Error = A::Error>
+{
+    type Item = Result<B::Ok, A::Error>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let state = mem::replace(self, AndThen::Done);
+
+        let mut b = match state {
+            AndThen::First(mut a, f) => {
+                match a.poll() {
+                    Async::NotReady => {
+                        *self = AndThen::First(a, f);
+                        return Async::NotReady;
+                    }
+                    Async::Ready(Err(e)) => return Async::Ready(Err(e)),
+                    Async::Ready(Ok(ok)) => f(ok),
+                }
+            }
+            AndThen::Second(b) => b,
+            AndThen::Done => panic!("cannot poll `and_then` twice"),
+        };
+
+        match b.poll() {
+            Async::NotReady => {
+                *self = AndThen::Second(b);
+                Async::NotReady
+            }
+            Async::Ready(result) => {
+                *self = AndThen::Done;
+                Async::Ready(result)
+            }
+        }
+    }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub enum OrElse<A, B, F> {
+    First(A, F),
+    Second(B),
+    Done,
+}
+
+impl<A, B, F> Future for OrElse<A, B, F>
+    where A: TryFuture,
+          F: FnOnce(A::Error) -> B,
+          B: TryFuture<Ok = A::Ok>
+{
+    type Item = Result<A::Ok, B::Error>;
+
+    fn poll(&mut self) -> Async<Self::Item> {
+        let state = mem::replace(self, OrElse::Done);
+
+        let mut b = match state {
+            OrElse::First(mut a, f) => {
+                match a.poll() {
+                    Async::NotReady => {
+                        *self = OrElse::First(a, f);
+                        return Async::NotReady;
+                    }
+                    Async::Ready(Ok(ok)) => return Async::Ready(Ok(ok)),
+                    Async::Ready(Err(e)) => f(e),
+                }
+            }
+            OrElse::Second(b) => b,
+            OrElse::Done => panic!("cannot poll `or_else` twice"),
+        };
+
+        match b.poll() {
+            Async::NotReady => {
+                *self = OrElse::Second(b);
+                Async::NotReady
+            }
+            Async::Ready(result) => {
+                *self = OrElse::Done;
+                Async::Ready(result)
+            }
+        }
+    }
+}